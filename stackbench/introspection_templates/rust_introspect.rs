@@ -2,6 +2,7 @@
 ```cargo
 [dependencies]
 syn = { version = "2.0", features = ["full", "visit"] }
+quote = "1.0"
 serde = { version = "1.0", features = ["derive"] }
 serde_json = "1.0"
 ```
@@ -14,11 +15,11 @@ serde_json = "1.0"
  * Uses syn crate to parse Rust source and extract public items.
  *
  * Usage:
- *     cargo +nightly -Zscript rust_introspect.rs <crate_name> <version> [modules...]
+ *     cargo +nightly -Zscript rust_introspect.rs <crate_name> <version> [source_files...] [--format default|rustdoc-json]
  *     OR
  *     rustc rust_introspect.rs && ./rust_introspect <crate_name> <version>
  *
- * Output (stdout):
+ * Output (stdout), `--format default` (the default):
  *     {
  *       "library": "serde",
  *       "version": "1.0.0",
@@ -27,15 +28,19 @@ serde_json = "1.0"
  *       "apis": [...],
  *       "by_type": {...}
  *     }
+ *
+ * `--format rustdoc-json` instead emits the shape `cargo +nightly rustdoc
+ * --output-format json` produces: a crate root id, a version, and an index
+ * of item records keyed by synthesized ids.
  */
 
 use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 use std::env;
 use std::fs;
-use std::path::Path;
+use std::path::{Path, PathBuf};
 use syn::visit::Visit;
-use syn::{Item, ItemFn, ItemMod, ItemStruct, ItemEnum, ItemTrait, Visibility};
+use syn::{ItemEnum, ItemFn, ItemImpl, ItemMod, ItemStruct, ItemTrait, Visibility};
 
 #[derive(Debug, Serialize, Deserialize)]
 struct APIMetadata {
@@ -47,9 +52,44 @@ struct APIMetadata {
     has_docstring: bool,
     in_all: bool, // pub (public visibility)
     is_deprecated: bool,
+    deprecation: Option<DeprecationInfo>,
+    // Full path of the item whose own attribute produced `deprecation` (this
+    // item's own path if not inherited), so two items inheriting from the
+    // same ancestor can be recognized as one propagated deprecation rather
+    // than counted as independent ones.
+    deprecation_origin: Option<String>,
+    // True when `deprecation` came from an enclosing module, struct, enum,
+    // or trait rather than this item's own attributes.
+    deprecation_inherited: bool,
+    stability: Option<Stability>,
+    stability_origin: Option<String>,
+    // True when `stability` came from an enclosing module, struct, enum, or
+    // trait rather than this item's own attributes.
+    stability_inherited: bool,
     signature: String,
 }
 
+/// Structured data carried by `#[deprecated(since = "...", note = "...")]`
+/// (and its compiler-internal cousin `#[rustc_deprecated(...)]`), so
+/// consumers get migration guidance instead of just a flag.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct DeprecationInfo {
+    since: Option<String>,
+    note: Option<String>,
+    suggestion: Option<String>,
+}
+
+/// Mirrors rustc's `#[stable(...)]` / `#[unstable(...)]` attributes (and the
+/// `rustc_const_stable` / `rustc_const_unstable` variants gating const-ness),
+/// so callers can tell which fraction of a crate's API surface is stabilized.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct Stability {
+    level: String, // "stable" or "unstable"
+    feature: Option<String>,
+    since: Option<String>,
+    issue: Option<String>,
+}
+
 #[derive(Debug, Serialize, Deserialize)]
 struct IntrospectionOutput {
     library: String,
@@ -61,25 +101,132 @@ struct IntrospectionOutput {
     deprecated_count: usize,
 }
 
+/// Subset of the shape emitted by `cargo +nightly rustdoc --output-format
+/// json`: a crate root id, a version, and an index of item records keyed by
+/// synthesized ids. Lets downstream tooling built against rustdoc's JSON
+/// backend ingest StackBench output without a custom adapter.
+#[derive(Debug, Serialize, Deserialize)]
+struct RustdocJsonOutput {
+    root: String,
+    crate_version: Option<String>,
+    index: HashMap<String, RustdocJsonItem>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct RustdocJsonItem {
+    name: String,
+    kind: String,
+    visibility: String,
+    deprecation: Option<RustdocJsonDeprecation>,
+    inner: serde_json::Value,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct RustdocJsonDeprecation {
+    since: Option<String>,
+    note: Option<String>,
+}
+
 /// Check if item is public
 fn is_public(vis: &Visibility) -> bool {
     matches!(vis, Visibility::Public(_))
 }
 
-/// Check if documentation contains deprecation notice
-fn is_deprecated(attrs: &[syn::Attribute]) -> bool {
-    attrs.iter().any(|attr| {
-        if attr.path().is_ident("deprecated") {
-            return true;
+/// Parse `#[deprecated]` / `#[deprecated(since = "...", note = "...")]`
+/// (and the compiler-internal `#[rustc_deprecated(...)]` variant, which also
+/// carries a `suggestion` replacement snippet) into structured data.
+fn parse_deprecation(attrs: &[syn::Attribute]) -> Option<DeprecationInfo> {
+    attrs.iter().find_map(|attr| {
+        if !(attr.path().is_ident("deprecated") || attr.path().is_ident("rustc_deprecated")) {
+            return None;
         }
-        if let Ok(meta) = attr.parse_meta() {
-            if let syn::Meta::NameValue(nv) = meta {
-                if let syn::Lit::Str(s) = &nv.lit {
-                    return s.value().to_lowercase().contains("deprecated");
-                }
+
+        match &attr.meta {
+            // Bare `#[deprecated]`
+            syn::Meta::Path(_) => Some(DeprecationInfo {
+                since: None,
+                note: None,
+                suggestion: None,
+            }),
+            // `#[deprecated(since = "...", note = "...", suggestion = "...")]`
+            syn::Meta::List(list) => {
+                let mut info = DeprecationInfo {
+                    since: None,
+                    note: None,
+                    suggestion: None,
+                };
+                let _ = list.parse_nested_meta(|nested| {
+                    let value = nested.value()?;
+                    let lit: syn::LitStr = value.parse()?;
+                    if nested.path.is_ident("since") {
+                        info.since = Some(lit.value());
+                    } else if nested.path.is_ident("note") || nested.path.is_ident("reason") {
+                        info.note = Some(lit.value());
+                    } else if nested.path.is_ident("suggestion") {
+                        info.suggestion = Some(lit.value());
+                    }
+                    Ok(())
+                });
+                Some(info)
+            }
+            // `#[deprecated = "note"]`
+            syn::Meta::NameValue(nv) => {
+                let note = match &nv.value {
+                    syn::Expr::Lit(syn::ExprLit {
+                        lit: syn::Lit::Str(s),
+                        ..
+                    }) => Some(s.value()),
+                    _ => None,
+                };
+                Some(DeprecationInfo {
+                    since: None,
+                    note,
+                    suggestion: None,
+                })
             }
         }
-        false
+    })
+}
+
+/// Parse `#[stable(feature = "...", since = "...")]` /
+/// `#[unstable(feature = "...", issue = "...")]` (and the const-fn variants
+/// `rustc_const_stable` / `rustc_const_unstable`) into structured data.
+fn parse_stability(attrs: &[syn::Attribute]) -> Option<Stability> {
+    attrs.iter().find_map(|attr| {
+        let level = if attr.path().is_ident("stable") || attr.path().is_ident("rustc_const_stable")
+        {
+            "stable"
+        } else if attr.path().is_ident("unstable") || attr.path().is_ident("rustc_const_unstable") {
+            "unstable"
+        } else {
+            return None;
+        };
+
+        let mut feature = None;
+        let mut since = None;
+        let mut issue = None;
+
+        if let syn::Meta::List(list) = &attr.meta {
+            let _ = list.parse_nested_meta(|nested| {
+                let value = nested.value()?;
+                let lit: syn::LitStr = value.parse()?;
+                if nested.path.is_ident("feature") {
+                    feature = Some(lit.value());
+                } else if nested.path.is_ident("since") {
+                    since = Some(lit.value());
+                } else if nested.path.is_ident("issue") {
+                    issue = Some(lit.value());
+                }
+                Ok(())
+            });
+        }
+
+        Some(Stability {
+            level: level.to_string(),
+            feature,
+            since,
+            issue,
+        })
     })
 }
 
@@ -96,7 +243,9 @@ fn get_fn_signature(sig: &syn::Signature) -> String {
         match arg {
             syn::FnArg::Receiver(_) => "self".to_string(),
             syn::FnArg::Typed(pat_type) => {
-                format!("{}: {}", quote::quote!(#pat_type.pat), quote::quote!(#pat_type.ty))
+                let pat = &pat_type.pat;
+                let ty = &pat_type.ty;
+                format!("{}: {}", quote::quote!(#pat), quote::quote!(#ty))
             }
         }
     }).collect();
@@ -109,19 +258,69 @@ fn get_fn_signature(sig: &syn::Signature) -> String {
     format!("({}){}", inputs.join(", "), output)
 }
 
+/// Render a type's base path for use in an API path or lookup key: just the
+/// segment identifiers, dropping any generic arguments. `quote!` alone
+/// renders `Foo<T>` as `"Foo < T >"`, which produces malformed, spaced-out
+/// API paths like `mycrate::Foo < T >::make`.
+fn base_type_path(ty: &syn::Type) -> String {
+    if let syn::Type::Path(type_path) = ty {
+        type_path
+            .path
+            .segments
+            .iter()
+            .map(|segment| segment.ident.to_string())
+            .collect::<Vec<_>>()
+            .join("::")
+    } else {
+        quote::quote!(#ty).to_string()
+    }
+}
+
 /// Visitor to collect public APIs
 struct APICollector {
     apis: Vec<APIMetadata>,
     module_path: Vec<String>,
     crate_name: String,
+    // (origin, value) entries for deprecation/stability inherited lexically
+    // from an enclosing module or trait, mirroring rustc's stability
+    // annotation pass: pushed on entry to a `mod { ... }` or `trait { ... }`
+    // body and popped on exit, so only items actually nested under the
+    // pusher see them. `origin` is the full path of the item that introduced
+    // the entry.
+    deprecation_stack: Vec<(String, DeprecationInfo)>,
+    stability_stack: Vec<(String, Stability)>,
+    // A struct/enum's effective (origin, value) deprecation/stability, keyed
+    // by its full path (via `qualify`, the same helper an inherent impl uses
+    // to build its own lookup key from `base_type_path(self_ty)`, so a
+    // generic `Foo<T>` keys identically on both sides). An inherent impl's
+    // methods are a *sibling* item in the AST, not nested under their
+    // struct/enum, so they can't see the stacks above; they look themselves
+    // up here instead. Entries are never popped, so an impl block visited
+    // after its type finds the entry wherever it sits; an impl visited
+    // before its type's definition finds none and falls back to the
+    // lexical stacks.
+    type_deprecations: HashMap<String, (String, DeprecationInfo)>,
+    type_stabilities: HashMap<String, (String, Stability)>,
+    // Directory `mod foo;` declarations in the module currently being
+    // visited resolve against (see `submodule_dir`).
+    current_dir: PathBuf,
+    // Files already walked, so a `mod` cycle (or a diamond re-declaration)
+    // doesn't recurse forever.
+    visited_files: HashSet<PathBuf>,
 }
 
 impl APICollector {
-    fn new(crate_name: String) -> Self {
+    fn new(crate_name: String, root_dir: PathBuf) -> Self {
         Self {
             apis: Vec::new(),
             module_path: vec![crate_name.clone()],
             crate_name,
+            deprecation_stack: Vec::new(),
+            stability_stack: Vec::new(),
+            type_deprecations: HashMap::new(),
+            type_stabilities: HashMap::new(),
+            current_dir: root_dir,
+            visited_files: HashSet::new(),
         }
     }
 
@@ -129,9 +328,120 @@ impl APICollector {
         self.module_path.join("::")
     }
 
+    /// Qualify a bare item name with the current module path. Used both for
+    /// an API's own `api` field and as the `type_deprecations`/
+    /// `type_stabilities` key, so a struct/enum's recorded key and an
+    /// inherent impl's lookup key are always built the identical way.
+    fn qualify(&self, name: &str) -> String {
+        format!("{}::{}", self.current_module(), name)
+    }
+
     fn add_api(&mut self, metadata: APIMetadata) {
         self.apis.push(metadata);
     }
+
+    /// Resolve `mod <name>;` to a source file, following Rust's lookup
+    /// rules: `<dir>/<name>.rs`, then `<dir>/<name>/mod.rs`.
+    fn resolve_mod_file(&self, name: &str) -> Option<PathBuf> {
+        let flat = self.current_dir.join(format!("{}.rs", name));
+        if flat.exists() {
+            return Some(flat);
+        }
+
+        let nested = self.current_dir.join(name).join("mod.rs");
+        if nested.exists() {
+            return Some(nested);
+        }
+
+        None
+    }
+
+    /// Directory further `mod foo;` declarations inside `file_path` resolve
+    /// against. A `mod.rs` sits *in* its module's directory, so siblings
+    /// resolve alongside it; any other file (`parser.rs`) is the module
+    /// itself, and Rust looks for its submodules in a same-named directory
+    /// next to it (`parser/lexer.rs` for `parser.rs`'s `mod lexer;`), not in
+    /// the directory the file happens to live in.
+    fn submodule_dir(&self, file_path: &Path, previous_dir: &Path) -> PathBuf {
+        let parent = file_path.parent().unwrap_or(previous_dir);
+        if file_path.file_name().and_then(|n| n.to_str()) == Some("mod.rs") {
+            parent.to_path_buf()
+        } else {
+            let stem = file_path.file_stem().and_then(|s| s.to_str()).unwrap_or("");
+            parent.join(stem)
+        }
+    }
+
+    /// Parse and walk a non-inline module's source file, switching
+    /// `current_dir` to its submodule directory (see `submodule_dir`) so
+    /// further nested `mod` chains resolve relative to *it*, not the
+    /// original crate root.
+    fn visit_external_mod_file(&mut self, file_path: &Path) {
+        let canonical = file_path
+            .canonicalize()
+            .unwrap_or_else(|_| file_path.to_path_buf());
+        if !self.visited_files.insert(canonical) {
+            return;
+        }
+
+        let code = match fs::read_to_string(file_path) {
+            Ok(code) => code,
+            Err(e) => {
+                eprintln!("ERROR: Failed to read {}: {}", file_path.display(), e);
+                return;
+            }
+        };
+        let syntax_tree = match syn::parse_file(&code) {
+            Ok(tree) => tree,
+            Err(e) => {
+                eprintln!("ERROR: Failed to parse {}: {}", file_path.display(), e);
+                return;
+            }
+        };
+
+        let previous_dir = self.current_dir.clone();
+        self.current_dir = self.submodule_dir(file_path, &previous_dir);
+
+        for item in &syntax_tree.items {
+            self.visit_item(item);
+        }
+
+        self.current_dir = previous_dir;
+    }
+
+    /// Resolve an item's effective deprecation: its own attribute if present,
+    /// otherwise whatever `fallback` provides (the nearest enclosing lexical
+    /// scope for most items, or a type's recorded entry for inherent impl
+    /// members). Returns the value, the origin that produced it (the item's
+    /// own path if not inherited), and whether it was inherited.
+    fn resolve_deprecation(
+        own: Option<DeprecationInfo>,
+        own_origin: &str,
+        fallback: Option<&(String, DeprecationInfo)>,
+    ) -> (Option<DeprecationInfo>, Option<String>, bool) {
+        match own {
+            Some(info) => (Some(info), Some(own_origin.to_string()), false),
+            None => match fallback {
+                Some((origin, info)) => (Some(info.clone()), Some(origin.clone()), true),
+                None => (None, None, false),
+            },
+        }
+    }
+
+    /// Same as `resolve_deprecation`, for stability attributes.
+    fn resolve_stability(
+        own: Option<Stability>,
+        own_origin: &str,
+        fallback: Option<&(String, Stability)>,
+    ) -> (Option<Stability>, Option<String>, bool) {
+        match own {
+            Some(info) => (Some(info), Some(own_origin.to_string()), false),
+            None => match fallback {
+                Some((origin, info)) => (Some(info.clone()), Some(origin.clone()), true),
+                None => (None, None, false),
+            },
+        }
+    }
 }
 
 impl<'ast> Visit<'ast> for APICollector {
@@ -139,6 +449,16 @@ impl<'ast> Visit<'ast> for APICollector {
         if is_public(&node.vis) {
             let api_name = node.sig.ident.to_string();
             let full_name = format!("{}::{}", self.current_module(), api_name);
+            let (deprecation, deprecation_origin, deprecation_inherited) = Self::resolve_deprecation(
+                parse_deprecation(&node.attrs),
+                &full_name,
+                self.deprecation_stack.last(),
+            );
+            let (stability, stability_origin, stability_inherited) = Self::resolve_stability(
+                parse_stability(&node.attrs),
+                &full_name,
+                self.stability_stack.last(),
+            );
 
             self.add_api(APIMetadata {
                 api: full_name,
@@ -147,7 +467,13 @@ impl<'ast> Visit<'ast> for APICollector {
                 is_async: node.sig.asyncness.is_some(),
                 has_docstring: has_docstring(&node.attrs),
                 in_all: true,
-                is_deprecated: is_deprecated(&node.attrs),
+                is_deprecated: deprecation.is_some(),
+                deprecation,
+                deprecation_origin,
+                deprecation_inherited,
+                stability,
+                stability_origin,
+                stability_inherited,
                 signature: get_fn_signature(&node.sig),
             });
         }
@@ -159,7 +485,29 @@ impl<'ast> Visit<'ast> for APICollector {
     fn visit_item_struct(&mut self, node: &'ast ItemStruct) {
         if is_public(&node.vis) {
             let struct_name = node.ident.to_string();
-            let full_name = format!("{}::{}", self.current_module(), struct_name);
+            let full_name = self.qualify(&struct_name);
+            let (deprecation, deprecation_origin, deprecation_inherited) = Self::resolve_deprecation(
+                parse_deprecation(&node.attrs),
+                &full_name,
+                self.deprecation_stack.last(),
+            );
+            let (stability, stability_origin, stability_inherited) = Self::resolve_stability(
+                parse_stability(&node.attrs),
+                &full_name,
+                self.stability_stack.last(),
+            );
+
+            // Record the struct's effective deprecation/stability so its
+            // inherent impl's members — a sibling item in the AST, not
+            // lexically nested here — can inherit it too.
+            if let (Some(info), Some(origin)) = (&deprecation, &deprecation_origin) {
+                self.type_deprecations
+                    .insert(full_name.clone(), (origin.clone(), info.clone()));
+            }
+            if let (Some(info), Some(origin)) = (&stability, &stability_origin) {
+                self.type_stabilities
+                    .insert(full_name.clone(), (origin.clone(), info.clone()));
+            }
 
             self.add_api(APIMetadata {
                 api: full_name,
@@ -168,7 +516,13 @@ impl<'ast> Visit<'ast> for APICollector {
                 is_async: false,
                 has_docstring: has_docstring(&node.attrs),
                 in_all: true,
-                is_deprecated: is_deprecated(&node.attrs),
+                is_deprecated: deprecation.is_some(),
+                deprecation,
+                deprecation_origin,
+                deprecation_inherited,
+                stability,
+                stability_origin,
+                stability_inherited,
                 signature: format!("struct {}", struct_name),
             });
         }
@@ -179,7 +533,26 @@ impl<'ast> Visit<'ast> for APICollector {
     fn visit_item_enum(&mut self, node: &'ast ItemEnum) {
         if is_public(&node.vis) {
             let enum_name = node.ident.to_string();
-            let full_name = format!("{}::{}", self.current_module(), enum_name);
+            let full_name = self.qualify(&enum_name);
+            let (deprecation, deprecation_origin, deprecation_inherited) = Self::resolve_deprecation(
+                parse_deprecation(&node.attrs),
+                &full_name,
+                self.deprecation_stack.last(),
+            );
+            let (stability, stability_origin, stability_inherited) = Self::resolve_stability(
+                parse_stability(&node.attrs),
+                &full_name,
+                self.stability_stack.last(),
+            );
+
+            if let (Some(info), Some(origin)) = (&deprecation, &deprecation_origin) {
+                self.type_deprecations
+                    .insert(full_name.clone(), (origin.clone(), info.clone()));
+            }
+            if let (Some(info), Some(origin)) = (&stability, &stability_origin) {
+                self.type_stabilities
+                    .insert(full_name.clone(), (origin.clone(), info.clone()));
+            }
 
             self.add_api(APIMetadata {
                 api: full_name,
@@ -188,7 +561,13 @@ impl<'ast> Visit<'ast> for APICollector {
                 is_async: false,
                 has_docstring: has_docstring(&node.attrs),
                 in_all: true,
-                is_deprecated: is_deprecated(&node.attrs),
+                is_deprecated: deprecation.is_some(),
+                deprecation,
+                deprecation_origin,
+                deprecation_inherited,
+                stability,
+                stability_origin,
+                stability_inherited,
                 signature: format!("enum {}", enum_name),
             });
         }
@@ -197,26 +576,68 @@ impl<'ast> Visit<'ast> for APICollector {
     }
 
     fn visit_item_trait(&mut self, node: &'ast ItemTrait) {
+        let own_deprecation = parse_deprecation(&node.attrs);
+        let own_stability = parse_stability(&node.attrs);
+
         if is_public(&node.vis) {
             let trait_name = node.ident.to_string();
             let full_name = format!("{}::{}", self.current_module(), trait_name);
+            let (deprecation, deprecation_origin, deprecation_inherited) = Self::resolve_deprecation(
+                own_deprecation.clone(),
+                &full_name,
+                self.deprecation_stack.last(),
+            );
+            let (stability, stability_origin, stability_inherited) = Self::resolve_stability(
+                own_stability.clone(),
+                &full_name,
+                self.stability_stack.last(),
+            );
 
             self.add_api(APIMetadata {
-                api: full_name,
+                api: full_name.clone(),
                 module: self.current_module(),
                 api_type: "class".to_string(),
                 is_async: false,
                 has_docstring: has_docstring(&node.attrs),
                 in_all: true,
-                is_deprecated: is_deprecated(&node.attrs),
+                is_deprecated: deprecation.is_some(),
+                deprecation,
+                deprecation_origin,
+                deprecation_inherited,
+                stability,
+                stability_origin,
+                stability_inherited,
                 signature: format!("trait {}", trait_name),
             });
 
+            // Enter: trait methods with no attributes of their own inherit
+            // the trait's deprecation/stability.
+            let pushed_dep = own_deprecation.is_some();
+            if let Some(info) = own_deprecation {
+                self.deprecation_stack.push((full_name.clone(), info));
+            }
+            let pushed_stab = own_stability.is_some();
+            if let Some(info) = own_stability {
+                self.stability_stack.push((full_name.clone(), info));
+            }
+
             // Also collect trait methods
             for item in &node.items {
-                if let syn::TraitItem::Method(method) = item {
+                if let syn::TraitItem::Fn(method) = item {
                     let method_name = method.sig.ident.to_string();
                     let method_full_name = format!("{}::{}", full_name, method_name);
+                    let (method_deprecation, method_deprecation_origin, method_deprecation_inherited) =
+                        Self::resolve_deprecation(
+                            parse_deprecation(&method.attrs),
+                            &method_full_name,
+                            self.deprecation_stack.last(),
+                        );
+                    let (method_stability, method_stability_origin, method_stability_inherited) =
+                        Self::resolve_stability(
+                            parse_stability(&method.attrs),
+                            &method_full_name,
+                            self.stability_stack.last(),
+                        );
 
                     self.add_api(APIMetadata {
                         api: method_full_name,
@@ -225,26 +646,203 @@ impl<'ast> Visit<'ast> for APICollector {
                         is_async: method.sig.asyncness.is_some(),
                         has_docstring: has_docstring(&method.attrs),
                         in_all: true,
-                        is_deprecated: is_deprecated(&method.attrs),
+                        is_deprecated: method_deprecation.is_some(),
+                        deprecation: method_deprecation,
+                        deprecation_origin: method_deprecation_origin,
+                        deprecation_inherited: method_deprecation_inherited,
+                        stability: method_stability,
+                        stability_origin: method_stability_origin,
+                        stability_inherited: method_stability_inherited,
                         signature: get_fn_signature(&method.sig),
                     });
                 }
             }
+
+            if pushed_dep {
+                self.deprecation_stack.pop();
+            }
+            if pushed_stab {
+                self.stability_stack.pop();
+            }
         }
 
         syn::visit::visit_item_trait(self, node);
     }
 
+    fn visit_item_impl(&mut self, node: &'ast ItemImpl) {
+        // Trait impls just (re)implement an interface collected elsewhere;
+        // only inherent impls introduce new public API surface.
+        if node.trait_.is_none() {
+            let type_name = base_type_path(&node.self_ty);
+            let type_path = self.qualify(&type_name);
+
+            // An inherent impl's members fall back to the type's recorded
+            // deprecation/stability (see `type_deprecations`/
+            // `type_stabilities`) before the enclosing lexical scope, since
+            // the type is the more specific ancestor. Cloned up front so the
+            // loop below can still mutably borrow `self` via `add_api`.
+            let type_dep_fallback = self
+                .type_deprecations
+                .get(&type_path)
+                .or_else(|| self.deprecation_stack.last())
+                .cloned();
+            let type_stab_fallback = self
+                .type_stabilities
+                .get(&type_path)
+                .or_else(|| self.stability_stack.last())
+                .cloned();
+
+            for item in &node.items {
+                match item {
+                    syn::ImplItem::Fn(method) if is_public(&method.vis) => {
+                        let method_name = method.sig.ident.to_string();
+                        let full_name = format!("{}::{}", type_path, method_name);
+                        let (deprecation, deprecation_origin, deprecation_inherited) =
+                            Self::resolve_deprecation(
+                                parse_deprecation(&method.attrs),
+                                &full_name,
+                                type_dep_fallback.as_ref(),
+                            );
+                        let (stability, stability_origin, stability_inherited) =
+                            Self::resolve_stability(
+                                parse_stability(&method.attrs),
+                                &full_name,
+                                type_stab_fallback.as_ref(),
+                            );
+
+                        self.add_api(APIMetadata {
+                            api: full_name,
+                            module: self.current_module(),
+                            api_type: "method".to_string(),
+                            is_async: method.sig.asyncness.is_some(),
+                            has_docstring: has_docstring(&method.attrs),
+                            in_all: true,
+                            is_deprecated: deprecation.is_some(),
+                            deprecation,
+                            deprecation_origin,
+                            deprecation_inherited,
+                            stability,
+                            stability_origin,
+                            stability_inherited,
+                            signature: get_fn_signature(&method.sig),
+                        });
+                    }
+                    syn::ImplItem::Const(assoc_const) if is_public(&assoc_const.vis) => {
+                        let const_name = assoc_const.ident.to_string();
+                        let full_name = format!("{}::{}", type_path, const_name);
+                        let ty = &assoc_const.ty;
+                        let (deprecation, deprecation_origin, deprecation_inherited) =
+                            Self::resolve_deprecation(
+                                parse_deprecation(&assoc_const.attrs),
+                                &full_name,
+                                type_dep_fallback.as_ref(),
+                            );
+                        let (stability, stability_origin, stability_inherited) =
+                            Self::resolve_stability(
+                                parse_stability(&assoc_const.attrs),
+                                &full_name,
+                                type_stab_fallback.as_ref(),
+                            );
+
+                        self.add_api(APIMetadata {
+                            api: full_name,
+                            module: self.current_module(),
+                            api_type: "property".to_string(),
+                            is_async: false,
+                            has_docstring: has_docstring(&assoc_const.attrs),
+                            in_all: true,
+                            is_deprecated: deprecation.is_some(),
+                            deprecation,
+                            deprecation_origin,
+                            deprecation_inherited,
+                            stability,
+                            stability_origin,
+                            stability_inherited,
+                            signature: format!("const {}: {}", const_name, quote::quote!(#ty)),
+                        });
+                    }
+                    syn::ImplItem::Type(assoc_type) if is_public(&assoc_type.vis) => {
+                        let assoc_type_name = assoc_type.ident.to_string();
+                        let full_name = format!("{}::{}", type_path, assoc_type_name);
+                        let ty = &assoc_type.ty;
+                        let (deprecation, deprecation_origin, deprecation_inherited) =
+                            Self::resolve_deprecation(
+                                parse_deprecation(&assoc_type.attrs),
+                                &full_name,
+                                type_dep_fallback.as_ref(),
+                            );
+                        let (stability, stability_origin, stability_inherited) =
+                            Self::resolve_stability(
+                                parse_stability(&assoc_type.attrs),
+                                &full_name,
+                                type_stab_fallback.as_ref(),
+                            );
+
+                        self.add_api(APIMetadata {
+                            api: full_name,
+                            module: self.current_module(),
+                            api_type: "type".to_string(),
+                            is_async: false,
+                            has_docstring: has_docstring(&assoc_type.attrs),
+                            in_all: true,
+                            is_deprecated: deprecation.is_some(),
+                            deprecation,
+                            deprecation_origin,
+                            deprecation_inherited,
+                            stability,
+                            stability_origin,
+                            stability_inherited,
+                            signature: format!("type {} = {}", assoc_type_name, quote::quote!(#ty)),
+                        });
+                    }
+                    _ => {}
+                }
+            }
+        }
+
+        syn::visit::visit_item_impl(self, node);
+    }
+
     fn visit_item_mod(&mut self, node: &'ast ItemMod) {
         if is_public(&node.vis) {
             // Enter module
             self.module_path.push(node.ident.to_string());
 
-            // Visit module contents
+            // A `#[deprecated] pub mod legacy` has no APIMetadata entry of
+            // its own, but its contents should inherit its deprecation and
+            // stability lexically.
+            let own_deprecation = parse_deprecation(&node.attrs);
+            let pushed_dep = own_deprecation.is_some();
+            if let Some(info) = own_deprecation {
+                self.deprecation_stack.push((self.current_module(), info));
+            }
+            let own_stability = parse_stability(&node.attrs);
+            let pushed_stab = own_stability.is_some();
+            if let Some(info) = own_stability {
+                self.stability_stack.push((self.current_module(), info));
+            }
+
+            // Visit module contents: inline (`mod foo { ... }`) or, for a
+            // plain declaration (`mod foo;`), resolved from the filesystem
+            // following Rust's lookup rules.
             if let Some((_, items)) = &node.content {
+                // `mod bar;` inside an inline `mod foo { ... }` resolves
+                // under `<dir>/foo/`.
+                let previous_dir = self.current_dir.clone();
+                self.current_dir = previous_dir.join(node.ident.to_string());
                 for item in items {
                     self.visit_item(item);
                 }
+                self.current_dir = previous_dir;
+            } else if let Some(file_path) = self.resolve_mod_file(&node.ident.to_string()) {
+                self.visit_external_mod_file(&file_path);
+            }
+
+            if pushed_dep {
+                self.deprecation_stack.pop();
+            }
+            if pushed_stab {
+                self.stability_stack.pop();
             }
 
             // Exit module
@@ -253,11 +851,97 @@ impl<'ast> Visit<'ast> for APICollector {
     }
 }
 
+/// Maps our `api_type` (plus, for `class`, the leading word of `signature`)
+/// to the item `kind` rustdoc-JSON uses.
+fn rustdoc_kind(api: &APIMetadata) -> String {
+    match api.api_type.as_str() {
+        "class" => {
+            if api.signature.starts_with("enum") {
+                "enum".to_string()
+            } else if api.signature.starts_with("trait") {
+                "trait".to_string()
+            } else {
+                "struct".to_string()
+            }
+        }
+        "property" => "assoc_const".to_string(),
+        "type" => "assoc_type".to_string(),
+        other => other.to_string(),
+    }
+}
+
+/// Re-serialize the collected APIs into rustdoc's experimental JSON-backend
+/// shape (`cargo +nightly rustdoc --output-format json`).
+fn to_rustdoc_json(crate_name: &str, version: &str, apis: &[APIMetadata]) -> RustdocJsonOutput {
+    let mut index = HashMap::new();
+
+    index.insert(
+        "0:0".to_string(),
+        RustdocJsonItem {
+            name: crate_name.to_string(),
+            kind: "module".to_string(),
+            visibility: "public".to_string(),
+            deprecation: None,
+            inner: serde_json::json!({ "is_crate": true }),
+        },
+    );
+
+    for (i, api) in apis.iter().enumerate() {
+        let id = format!("0:{}", i + 1);
+        let name = api
+            .api
+            .rsplit("::")
+            .next()
+            .unwrap_or(&api.api)
+            .to_string();
+        let deprecation = api.deprecation.as_ref().map(|d| RustdocJsonDeprecation {
+            since: d.since.clone(),
+            note: d.note.clone(),
+        });
+
+        index.insert(
+            id,
+            RustdocJsonItem {
+                name,
+                kind: rustdoc_kind(api),
+                visibility: if api.in_all {
+                    "public".to_string()
+                } else {
+                    "default".to_string()
+                },
+                deprecation,
+                inner: serde_json::json!({
+                    "path": api.api,
+                    "module": api.module,
+                    "signature": api.signature,
+                    "is_async": api.is_async,
+                    "has_docstring": api.has_docstring,
+                    "stability": api.stability,
+                    "deprecation_inherited": api.deprecation_inherited,
+                    "deprecation_origin": api.deprecation_origin,
+                    "stability_inherited": api.stability_inherited,
+                    "stability_origin": api.stability_origin,
+                }),
+            },
+        );
+    }
+
+    RustdocJsonOutput {
+        root: "0:0".to_string(),
+        crate_version: Some(version.to_string()),
+        index,
+    }
+}
+
 fn introspect_file(file_path: &Path, crate_name: &str) -> Result<Vec<APIMetadata>, Box<dyn std::error::Error>> {
     let code = fs::read_to_string(file_path)?;
     let syntax_tree = syn::parse_file(&code)?;
 
-    let mut collector = APICollector::new(crate_name.to_string());
+    let root_dir = file_path
+        .parent()
+        .map(Path::to_path_buf)
+        .unwrap_or_else(|| Path::new(".").to_path_buf());
+    let mut collector = APICollector::new(crate_name.to_string(), root_dir);
     collector.visit_file(&syntax_tree);
 
     Ok(collector.apis)
@@ -266,14 +950,32 @@ fn introspect_file(file_path: &Path, crate_name: &str) -> Result<Vec<APIMetadata
 fn main() {
     let args: Vec<String> = env::args().collect();
 
-    if args.len() < 3 {
-        eprintln!("Usage: {} <crate_name> <version> [source_files...]", args[0]);
+    // Pull `--format <name>` out of the argument list wherever it appears;
+    // everything else stays positional (crate_name, version, source_files).
+    let mut format = "default".to_string();
+    let mut positional: Vec<String> = Vec::new();
+    let mut rest = args[1..].iter();
+    while let Some(arg) = rest.next() {
+        if arg == "--format" {
+            if let Some(value) = rest.next() {
+                format = value.clone();
+            }
+        } else {
+            positional.push(arg.clone());
+        }
+    }
+
+    if positional.len() < 2 {
+        eprintln!(
+            "Usage: {} <crate_name> <version> [source_files...] [--format default|rustdoc-json]",
+            args[0]
+        );
         std::process::exit(1);
     }
 
-    let crate_name = &args[1];
-    let version = &args[2];
-    let source_files: Vec<&String> = args.get(3..).unwrap_or(&[]).iter().collect();
+    let crate_name = &positional[0];
+    let version = &positional[1];
+    let source_files: Vec<&String> = positional.get(2..).unwrap_or(&[]).iter().collect();
 
     let mut all_apis = Vec::new();
 
@@ -296,6 +998,12 @@ fn main() {
         }
     }
 
+    if format == "rustdoc-json" {
+        let output = to_rustdoc_json(crate_name, version, &all_apis);
+        print_json(&output);
+        return;
+    }
+
     // Count by type
     let mut by_type: HashMap<String, usize> = HashMap::new();
     let mut deprecated_count = 0;
@@ -318,8 +1026,12 @@ fn main() {
         deprecated_count,
     };
 
-    // Output JSON to stdout
-    match serde_json::to_string_pretty(&output) {
+    print_json(&output);
+}
+
+/// Serialize `value` to pretty JSON on stdout, or report the error.
+fn print_json<T: Serialize>(value: &T) {
+    match serde_json::to_string_pretty(value) {
         Ok(json) => println!("{}", json),
         Err(e) => {
             eprintln!("ERROR: Failed to serialize JSON: {}", e);